@@ -0,0 +1,44 @@
+import { describe, expect, test } from "bun:test";
+import {
+  BITS_PER_ID,
+  CHUNK_SIDE_SHIFT,
+  validateChunkLayout,
+  validateWorkgroupLimits,
+} from "./Config.ts";
+
+// WebGPU's default limits, which every adapter must meet or exceed.
+const DEFAULT_LIMITS = {
+  maxComputeWorkgroupSizeX: 256,
+  maxComputeWorkgroupSizeZ: 64,
+  maxComputeInvocationsPerWorkgroup: 256,
+};
+
+describe("validateChunkLayout", () => {
+  test("accepts the shipped layout", () => {
+    expect(() =>
+      validateChunkLayout(BITS_PER_ID, CHUNK_SIDE_SHIFT),
+    ).not.toThrow();
+  });
+
+  test("rejects ids that don't divide a word", () => {
+    expect(() => validateChunkLayout(6, 3)).toThrow("factor of 32");
+  });
+
+  test("rejects a column shorter than one word", () => {
+    expect(() => validateChunkLayout(4, 2)).toThrow("too small");
+  });
+});
+
+describe("validateWorkgroupLimits", () => {
+  test("accepts the shipped chunk side", () => {
+    expect(() =>
+      validateWorkgroupLimits(CHUNK_SIDE_SHIFT, DEFAULT_LIMITS),
+    ).not.toThrow();
+  });
+
+  test("rejects a workgroup over the invocation limit", () => {
+    expect(() => validateWorkgroupLimits(5, DEFAULT_LIMITS)).toThrow(
+      "workgroup limits",
+    );
+  });
+});
@@ -1,10 +1,49 @@
 export const GEN_SIDE = 256;
-export const BITS_PER_ID = 8; // must be a factor of 32
+export const BITS_PER_ID = 8; // see validateChunkLayout
 export const CHUNK_SIDE_SHIFT = 3;
 export const DAY_LENGTH_SECONDS = 600;
 export const MAX_CHUNK_BATCH_SIZE = 8192;
 export const MAX_CHUNKS_LOADED = 64_000;
 
+// Rejects chunk layouts the gen and read_pool shaders can't pack. The gen
+// pass writes whole words per column, so a column must span at least one word.
+export function validateChunkLayout(
+  bitsPerId: number,
+  chunkSideShift: number,
+): void {
+  if (32 % bitsPerId !== 0) {
+    throw new Error(`BITS_PER_ID (${bitsPerId}) must be a factor of 32`);
+  }
+  const chunkSide = 1 << chunkSideShift;
+  if ((chunkSide * bitsPerId) % 32 !== 0) {
+    throw new Error(
+      `Chunk side ${chunkSide} too small to pack ${bitsPerId}-bit ids per word`,
+    );
+  }
+}
+
+// gen_chunk runs one invocation per column, so its workgroup is side x 1 x side.
+export function validateWorkgroupLimits(
+  chunkSideShift: number,
+  limits: Pick<
+    GPUSupportedLimits,
+    | "maxComputeWorkgroupSizeX"
+    | "maxComputeWorkgroupSizeZ"
+    | "maxComputeInvocationsPerWorkgroup"
+  >,
+): void {
+  const chunkSide = 1 << chunkSideShift;
+  if (
+    chunkSide > limits.maxComputeWorkgroupSizeX ||
+    chunkSide > limits.maxComputeWorkgroupSizeZ ||
+    chunkSide * chunkSide > limits.maxComputeInvocationsPerWorkgroup
+  ) {
+    throw new Error(`Chunk side ${chunkSide} exceeds GPU workgroup limits`);
+  }
+}
+
+validateChunkLayout(BITS_PER_ID, CHUNK_SIDE_SHIFT);
+
 export const CHUNK_LEN = Math.ceil(
   ((1 << CHUNK_SIDE_SHIFT) ** 3 * BITS_PER_ID) / 32,
 );
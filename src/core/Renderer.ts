@@ -17,6 +17,8 @@ import {
   createConfig,
   GEN_SIDE,
   DAY_LENGTH_SECONDS,
+  CHUNK_SIDE_SHIFT,
+  validateWorkgroupLimits,
 } from "./Config.ts";
 import { Clock } from "../core/Clock.ts";
 
@@ -68,6 +70,7 @@ export class Renderer {
       ? ["timestamp-query"]
       : [];
     this.device = await adapter.requestDevice({ requiredFeatures });
+    validateWorkgroupLimits(CHUNK_SIDE_SHIFT, this.device.limits);
 
     this.context = this.canvas.getContext("webgpu")!;
     this.format = navigator.gpu.getPreferredCanvasFormat();